@@ -1,8 +1,11 @@
 use core::f32;
+use std::io;
 
 use parry3d::na::Isometry3;
 use stl_io::IndexedMesh;
 
+use rand::Rng;
+
 use parry3d::mass_properties::details::trimesh_signed_volume_and_center_of_mass;
 use parry3d::math::{Point, Vector};
 use parry3d::query::{Ray, RayCast};
@@ -11,18 +14,49 @@ use parry3d::utils::median;
 
 use parry3d::transformation::{self};
 
+/// Default number of rays cast into the SDF cone per triangle.
+pub const DEFAULT_SDF_RAY_COUNT: usize = 24;
+/// Default SDF cone half-angle, in degrees, measured from `-normal`.
+pub const DEFAULT_SDF_CONE_ANGLE_DEG: f32 = 30.0;
+
+/// Default sample count for [`StlMesh::monte_carlo_volume`].
+pub const DEFAULT_MONTE_CARLO_SAMPLES: usize = 20_000;
+/// Relative disagreement between the signed volume and the Monte Carlo
+/// volume above which the latter is surfaced as a sanity-check alternative.
+pub const VOLUME_DISAGREEMENT_THRESHOLD: f32 = 0.05;
+/// Below this fraction of the bounding-box volume, a non-negative signed
+/// volume is still treated as implausible (e.g. self-intersections folding
+/// most of the volume away) and worth the expensive Monte Carlo check.
+pub const MIN_PLAUSIBLE_VOLUME_FRACTION: f32 = 0.001;
+
+#[derive(serde::Serialize)]
 pub struct VolumeInfo {
     pub mesh: f32,
     pub bounding_box: f32,
+    #[serde(flatten)]
     pub thickness: Statistics,
     pub convex_volume: f32,
+    /// `(volume, standard_error)` from [`StlMesh::monte_carlo_volume`],
+    /// populated only when it disagrees with `mesh` beyond
+    /// [`VOLUME_DISAGREEMENT_THRESHOLD`] -- a cheap watertightness sanity
+    /// check for meshes with holes, flipped normals or self-intersections.
+    pub monte_carlo_volume: Option<(f32, f32)>,
 }
 
+#[derive(serde::Serialize)]
 pub struct Statistics {
     pub avg: f32,
     pub median: f32,
     pub std_dev: f32,
+    /// Per-triangle thickness samples; omitted from serialized output
+    /// unless the caller opts in (e.g. `--verbose`), by clearing this
+    /// vector before serializing.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub thicknesses: Vec<f32>,
+    /// Number of cone rays cast per triangle to estimate local thickness.
+    pub ray_count: usize,
+    /// SDF cone half-angle, in degrees, used to gauge local thickness.
+    pub cone_angle_deg: f32,
 }
 
 #[derive(Clone, Copy)]
@@ -68,6 +102,78 @@ impl StlMesh {
         trimesh_signed_volume_and_center_of_mass(self.mesh.vertices(), self.mesh.indices()).0
     }
 
+    /// Estimate the enclosed volume by Monte Carlo sampling, robust to the
+    /// holes, flipped normals and self-intersections that make
+    /// [`Self::mesh_volume`] unreliable on non-watertight meshes.
+    ///
+    /// `samples` uniformly-random points are drawn inside `local_aabb()`;
+    /// each is classified inside/outside by casting a ray in a fresh random
+    /// direction and counting triangle intersections (odd parity means
+    /// inside). Returns `(volume, standard_error)`, where the standard
+    /// error is `sqrt(p(1-p)/samples) * aabb_volume` so callers can report
+    /// a confidence interval.
+    pub fn monte_carlo_volume(&self, samples: usize) -> (f32, f32) {
+        let aabb = self.mesh.local_aabb();
+        let aabb_volume = aabb.volume();
+        let mut rng = rand::thread_rng();
+        let mut inside_count = 0usize;
+
+        for _ in 0..samples {
+            let point = Point::new(
+                rng.gen_range(aabb.mins.x..=aabb.maxs.x),
+                rng.gen_range(aabb.mins.y..=aabb.maxs.y),
+                rng.gen_range(aabb.mins.z..=aabb.maxs.z),
+            );
+
+            // A fresh random direction per sample avoids degenerate grazing
+            // hits repeatedly landing on shared triangle edges.
+            let direction = random_unit_vector(&mut rng);
+            let ray = Ray::new(point, direction);
+            if self.ray_intersection_count(&ray) % 2 == 1 {
+                inside_count += 1;
+            }
+        }
+
+        let p = inside_count as f32 / samples as f32;
+        let volume = p * aabb_volume;
+        let standard_error = (p * (1.0 - p) / samples as f32).sqrt() * aabb_volume;
+
+        (volume, standard_error)
+    }
+
+    /// Count how many times a ray intersects the mesh, for inside/outside
+    /// parity tests on meshes that may not be watertight. Uses the mesh's
+    /// own accelerated `cast_ray` (same path as [`Self::sdf_thickness_at`])
+    /// repeatedly, advancing the ray origin just past each hit, rather than
+    /// testing every triangle individually -- O(log n) per hit instead of
+    /// O(triangle_count) per sample.
+    fn ray_intersection_count(&self, ray: &Ray) -> usize {
+        // A small nudge past each hit to avoid re-hitting the same surface,
+        // and a defensive cap so pathological/self-intersecting geometry
+        // can't spin this loop forever.
+        const EPSILON: f32 = 1e-4;
+        const MAX_HITS: usize = 4096;
+
+        let mut count = 0usize;
+        let mut origin = ray.origin;
+
+        while count < MAX_HITS {
+            let remaining = Ray::new(origin, ray.dir);
+            match self
+                .mesh
+                .cast_ray(&Isometry3::identity(), &remaining, f32::MAX, false)
+            {
+                Some(toi) => {
+                    count += 1;
+                    origin = remaining.point_at(toi) + ray.dir * EPSILON;
+                }
+                None => break,
+            }
+        }
+
+        count
+    }
+
     pub fn facing_area(&self, plane_normal: &[f32]) -> f32 {
         if plane_normal.len() != 3 {
             panic!("Provide 3D normal");
@@ -95,7 +201,21 @@ impl StlMesh {
         total_area
     }
 
-    pub fn calculate_thickness(&self, outlier_range: Option<OutlierLimits>) -> Statistics {
+    /// Gauge per-triangle wall thickness with a Shape Diameter Function (SDF)
+    /// estimator: a cone of rays is cast from each triangle's midpoint into
+    /// the inward hemisphere around `-normal`, and the surviving hit
+    /// distances are reduced to a single robust thickness per triangle.
+    ///
+    /// `ray_count` rays are cast per triangle within a cone of half-angle
+    /// `cone_half_angle_deg` degrees around `-normal`. Pass
+    /// [`DEFAULT_SDF_RAY_COUNT`] / [`DEFAULT_SDF_CONE_ANGLE_DEG`] for sane
+    /// defaults.
+    pub fn calculate_thickness(
+        &self,
+        outlier_range: Option<OutlierLimits>,
+        ray_count: usize,
+        cone_half_angle_deg: f32,
+    ) -> Statistics {
         let mut thicknesses: Vec<f32> = Vec::new();
         let mut areas: Vec<f32> = Vec::new();
 
@@ -107,12 +227,22 @@ impl StlMesh {
             },
         };
 
+        let cone_half_angle = cone_half_angle_deg.to_radians();
+        let mut rng = rand::thread_rng();
+
         for triangle in self.mesh.triangles() {
             let p1: Point<f32> = triangle.a;
             let p2: Point<f32> = triangle.b;
             let p3: Point<f32> = triangle.c;
 
-            let n = triangle.normal().unwrap().into_inner();
+            // Degenerate (zero-area/collinear) triangles have no normal;
+            // these show up in real OBJ/PLY exports (duplicate vertices,
+            // collinear fan-triangulated faces) and should be skipped
+            // rather than taking down the whole batch.
+            let n = match triangle.normal() {
+                Some(n) => n.into_inner(),
+                None => continue,
+            };
 
             let midpoint: Point<f32> = Point::new(
                 (p1.x + p2.x + p3.x) / 3.0,
@@ -120,18 +250,9 @@ impl StlMesh {
                 (p1.z + p2.z + p3.z) / 3.0,
             );
 
-            let mut ray = Ray::new(midpoint, n);
-            let da = self
-                .mesh
-                .cast_ray(&Isometry3::identity(), &ray, 100., false);
-
-            ray = Ray::new(midpoint, -n);
-            let db = self
-                .mesh
-                .cast_ray(&Isometry3::identity(), &ray, 100., false);
-
-            if let (Some(da), Some(db)) = (da, db) {
-                let thickness = if da > db { da } else { db };
+            if let Some(thickness) =
+                self.sdf_thickness_at(midpoint, n, ray_count, cone_half_angle, &mut rng)
+            {
                 if thickness > bounds.min && thickness < bounds.max {
                     thicknesses.push(thickness);
                     areas.push(triangle.area());
@@ -154,6 +275,75 @@ impl StlMesh {
             avg,
             median,
             thicknesses,
+            ray_count,
+            cone_angle_deg: cone_half_angle_deg,
+        }
+    }
+
+    /// Cast a cone of rays from `origin` into the inward hemisphere around
+    /// `-normal` and reduce the surviving hit distances to a single robust
+    /// thickness estimate, or `None` if no ray produced a usable hit.
+    fn sdf_thickness_at(
+        &self,
+        origin: Point<f32>,
+        normal: Vector<f32>,
+        ray_count: usize,
+        cone_half_angle: f32,
+        rng: &mut impl Rng,
+    ) -> Option<f32> {
+        let inward = -normal;
+        let (u, v) = orthonormal_basis(&inward);
+
+        let mut distances: Vec<f32> = Vec::with_capacity(ray_count);
+        let mut weights: Vec<f32> = Vec::with_capacity(ray_count);
+
+        for _ in 0..ray_count {
+            // Random cone sampling: uniform over the solid angle within
+            // `cone_half_angle` of `inward`, uniform azimuth around it.
+            let cos_theta_min = cone_half_angle.cos();
+            let cos_theta = rng.gen_range(cos_theta_min..=1.0);
+            let sin_theta = (1.0 - cos_theta * cos_theta).max(0.0).sqrt();
+            let phi = rng.gen_range(0.0..std::f32::consts::TAU);
+
+            let direction = inward * cos_theta + (u * phi.cos() + v * phi.sin()) * sin_theta;
+
+            let ray = Ray::new(origin, direction);
+            if let Some(hit) =
+                self.mesh
+                    .cast_ray_and_get_normal(&Isometry3::identity(), &ray, 100., false)
+            {
+                // Reject same-facing hits: a hit whose normal still points
+                // roughly with the source normal is a spurious self-hit
+                // rather than the opposing wall.
+                if hit.normal.dot(&normal) > 0.0 {
+                    continue;
+                }
+                distances.push(hit.time_of_impact);
+                weights.push(cos_theta);
+            }
+        }
+
+        if distances.is_empty() {
+            return None;
+        }
+
+        let mut sorted = distances.clone();
+        let center = median(&mut sorted);
+        let spread = std(&distances, center);
+
+        let mut weighted_sum = 0.0;
+        let mut weight_total = 0.0;
+        for (d, w) in distances.iter().zip(weights.iter()) {
+            if (d - center).abs() <= spread {
+                weighted_sum += d * w;
+                weight_total += w;
+            }
+        }
+
+        if weight_total > 0.0 {
+            Some(weighted_sum / weight_total)
+        } else {
+            Some(center)
         }
     }
 
@@ -163,6 +353,36 @@ impl StlMesh {
             .expect("Could not create trimesh");
         StlMesh { mesh }
     }
+
+    /// Write the internal mesh out as a binary STL, recomputing per-face
+    /// normals from the geometry. Lets derived meshes (convex hulls today,
+    /// repaired or unit-scaled copies later) round-trip back to a file.
+    pub fn write_stl(&self, path: &std::path::Path) -> io::Result<()> {
+        let vertices = self.mesh.vertices();
+        let triangles: Vec<stl_io::Triangle> = self
+            .mesh
+            .indices()
+            .iter()
+            .map(|idx| {
+                let v0 = vertices[idx[0] as usize];
+                let v1 = vertices[idx[1] as usize];
+                let v2 = vertices[idx[2] as usize];
+                let normal = (v1 - v0).cross(&(v2 - v0)).normalize();
+
+                stl_io::Triangle {
+                    normal: stl_io::Normal::new([normal.x, normal.y, normal.z]),
+                    vertices: [
+                        stl_io::Vertex::new([v0.x, v0.y, v0.z]),
+                        stl_io::Vertex::new([v1.x, v1.y, v1.z]),
+                        stl_io::Vertex::new([v2.x, v2.y, v2.z]),
+                    ],
+                }
+            })
+            .collect();
+
+        let mut file = std::fs::File::create(path)?;
+        stl_io::write_stl(&mut file, triangles.iter())
+    }
 }
 
 impl From<&IndexedMesh> for StlMesh {
@@ -192,13 +412,136 @@ impl From<&IndexedMesh> for StlMesh {
     }
 }
 
+/// A PLY mesh as read off disk: vertex positions plus faces,
+/// which may still be arbitrary polygons pending fan-triangulation.
+pub struct PlyTriangleMesh {
+    pub vertices: Vec<[f32; 3]>,
+    pub polygons: Vec<Vec<u32>>,
+}
+
+impl TryFrom<&tobj::Mesh> for StlMesh {
+    type Error = io::Error;
+
+    fn try_from(mesh: &tobj::Mesh) -> Result<Self, Self::Error> {
+        if mesh.positions.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "OBJ mesh has no vertices",
+            ));
+        }
+
+        let vertices: Vec<Point<f32>> = mesh
+            .positions
+            .chunks(3)
+            .map(|v| Point::new(v[0], v[1], v[2]))
+            .collect();
+
+        // OBJ faces may be arbitrary polygons: `face_arities` is empty when
+        // tobj has already triangulated, otherwise it gives the vertex
+        // count of each face in `indices` in order.
+        let mut indices: Vec<[u32; 3]> = Vec::new();
+        if mesh.face_arities.is_empty() {
+            indices.extend(mesh.indices.chunks_exact(3).map(|c| [c[0], c[1], c[2]]));
+        } else {
+            let mut cursor = 0usize;
+            for &arity in &mesh.face_arities {
+                let arity = arity as usize;
+                indices.extend(fan_triangulate(&mesh.indices[cursor..cursor + arity]));
+                cursor += arity;
+            }
+        }
+
+        let mesh = TriMesh::with_flags(vertices, indices, TriMeshFlags::all())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+
+        Ok(StlMesh { mesh })
+    }
+}
+
+impl TryFrom<&PlyTriangleMesh> for StlMesh {
+    type Error = io::Error;
+
+    fn try_from(mesh: &PlyTriangleMesh) -> Result<Self, Self::Error> {
+        if mesh.vertices.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "PLY mesh has no vertices",
+            ));
+        }
+
+        let vertices: Vec<Point<f32>> = mesh
+            .vertices
+            .iter()
+            .map(|v| Point::new(v[0], v[1], v[2]))
+            .collect();
+
+        let indices: Vec<[u32; 3]> = mesh
+            .polygons
+            .iter()
+            .flat_map(|face| fan_triangulate(face))
+            .collect();
+
+        let mesh = TriMesh::with_flags(vertices, indices, TriMeshFlags::all())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+
+        Ok(StlMesh { mesh })
+    }
+}
+
+/// Fan-triangulate a (possibly non-triangular) polygon face given as a
+/// vertex index list, e.g. an OBJ/PLY quad `[a, b, c, d]` becomes the two
+/// triangles `[a, b, c]` and `[a, c, d]`.
+fn fan_triangulate(face: &[u32]) -> Vec<[u32; 3]> {
+    if face.len() < 3 {
+        return Vec::new();
+    }
+    (1..face.len() - 1)
+        .map(|i| [face[0], face[i], face[i + 1]])
+        .collect()
+}
+
 impl Into<VolumeInfo> for StlMesh {
     fn into(self) -> VolumeInfo {
+        let mesh_volume = self.mesh_volume();
+        let bounding_box = self.mesh.local_aabb().volume();
+        let convex_volume = self.convex().mesh_volume();
+
+        // The 20k-sample Monte Carlo estimate is expensive (it's still
+        // O(samples * log(triangle_count))), so only pay for it when the
+        // cheap signed volume already looks wrong: non-positive, NaN,
+        // implausibly small relative to the mesh's own bounding box, or
+        // geometrically impossible on the high end (self-intersections
+        // commonly fold volume *in*, producing an over-estimate rather
+        // than an under-estimate -- a solid's volume can never exceed its
+        // own bounding box or its own convex hull).
+        let looks_questionable = mesh_volume.is_nan()
+            || mesh_volume <= 0.0
+            || (bounding_box > 0.0
+                && (mesh_volume / bounding_box) < MIN_PLAUSIBLE_VOLUME_FRACTION)
+            || mesh_volume > bounding_box
+            || mesh_volume > convex_volume;
+
+        let monte_carlo_volume = looks_questionable
+            .then(|| {
+                let (mc_volume, mc_standard_error) =
+                    self.monte_carlo_volume(DEFAULT_MONTE_CARLO_SAMPLES);
+                let disagrees = mesh_volume.abs() < f32::EPSILON
+                    || ((mesh_volume - mc_volume).abs() / mesh_volume.abs())
+                        > VOLUME_DISAGREEMENT_THRESHOLD;
+                disagrees.then_some((mc_volume, mc_standard_error))
+            })
+            .flatten();
+
         VolumeInfo {
-            bounding_box: self.mesh.local_aabb().volume(),
-            thickness: self.calculate_thickness(None),
-            convex_volume: self.convex().mesh_volume(),
-            mesh: self.mesh_volume(),
+            bounding_box,
+            thickness: self.calculate_thickness(
+                None,
+                DEFAULT_SDF_RAY_COUNT,
+                DEFAULT_SDF_CONE_ANGLE_DEG,
+            ),
+            convex_volume,
+            mesh: mesh_volume,
+            monte_carlo_volume,
         }
     }
 }
@@ -218,6 +561,27 @@ fn std(it: &[f32], avg: f32) -> f32 {
     std_dev.sqrt()
 }
 
+/// Sample a uniformly-random direction on the unit sphere.
+fn random_unit_vector(rng: &mut impl Rng) -> Vector<f32> {
+    let z = rng.gen_range(-1.0..=1.0_f32);
+    let phi = rng.gen_range(0.0..std::f32::consts::TAU);
+    let r = (1.0 - z * z).max(0.0).sqrt();
+    Vector::new(r * phi.cos(), r * phi.sin(), z)
+}
+
+/// Build an orthonormal basis `(u, v)` spanning the plane perpendicular to
+/// `n`, for sampling directions around `n` as a cone axis.
+fn orthonormal_basis(n: &Vector<f32>) -> (Vector<f32>, Vector<f32>) {
+    let a = if n.x.abs() < 0.9 {
+        Vector::new(1.0, 0.0, 0.0)
+    } else {
+        Vector::new(0.0, 1.0, 0.0)
+    };
+    let u = n.cross(&a).normalize();
+    let v = n.cross(&u).normalize();
+    (u, v)
+}
+
 /// Project a 3D point onto a plane defined by its normal.
 fn project_point_onto_plane(point: &Point<f32>, plane_normal: &Vector<f32>) -> Point<f32> {
     let normal = plane_normal.normalize();
@@ -228,3 +592,107 @@ fn project_point_onto_plane(point: &Point<f32>, plane_normal: &Vector<f32>) -> P
         point.z - distance * normal.z,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    // A right-triangle top plate (half a unit square) at z=1 (outward
+    // normal +z) directly above the same triangle at z=0 with an *opposing*
+    // outward normal
+    // (-z, via reversed winding) -- the textbook case the SDF cone should
+    // gauge as roughly 1 unit of wall thickness.
+    const OPPOSING_PLATES_VERTICES: [f32; 18] = [
+        0.0, 0.0, 1.0, 1.0, 0.0, 1.0, 0.0, 1.0, 1.0, // top, normal +z
+        0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 1.0, 0.0, 0.0, // bottom, normal -z
+    ];
+    const OPPOSING_PLATES_INDICES: [u32; 6] = [0, 1, 2, 3, 4, 5];
+
+    // Same footprint, but the bottom plate keeps the top plate's winding,
+    // so both outward normals point +z -- every hit between them is
+    // same-facing and must be rejected by the SDF estimator.
+    const SAME_FACING_PLATES_VERTICES: [f32; 18] = [
+        0.0, 0.0, 1.0, 1.0, 0.0, 1.0, 0.0, 1.0, 1.0, // top, normal +z
+        0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, // bottom, normal +z too
+    ];
+    const SAME_FACING_PLATES_INDICES: [u32; 6] = [0, 1, 2, 3, 4, 5];
+
+    #[test]
+    fn sdf_thickness_at_finds_opposing_surface() {
+        let mesh = StlMesh::new(&OPPOSING_PLATES_VERTICES, &OPPOSING_PLATES_INDICES);
+        let origin = Point::new(0.25, 0.25, 1.0);
+        let normal = Vector::new(0.0, 0.0, 1.0);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+
+        let thickness = mesh.sdf_thickness_at(origin, normal, 1, 30f32.to_radians(), &mut rng);
+
+        assert!(thickness.is_some());
+        let thickness = thickness.unwrap();
+        assert!(
+            thickness > 0.0 && thickness < 5.0,
+            "expected a plausible thickness near 1.0, got {thickness}"
+        );
+    }
+
+    #[test]
+    fn sdf_thickness_at_rejects_same_facing_hits() {
+        let mesh = StlMesh::new(&SAME_FACING_PLATES_VERTICES, &SAME_FACING_PLATES_INDICES);
+        let origin = Point::new(0.25, 0.25, 1.0);
+        let normal = Vector::new(0.0, 0.0, 1.0);
+        let mut rng = rand::thread_rng();
+
+        let thickness = mesh.sdf_thickness_at(origin, normal, 8, 30f32.to_radians(), &mut rng);
+
+        assert!(thickness.is_none());
+    }
+
+    #[test]
+    fn sdf_thickness_at_returns_none_when_every_ray_misses() {
+        // A single triangle with nothing on the far side of any ray: no
+        // rays survive, so there is nothing to reduce to a thickness.
+        let vertices: [f32; 9] = [0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0];
+        let indices: [u32; 3] = [0, 1, 2];
+        let mesh = StlMesh::new(&vertices, &indices);
+        let origin = Point::new(0.25, 0.25, 0.0);
+        let normal = Vector::new(0.0, 0.0, 1.0);
+        let mut rng = rand::thread_rng();
+
+        let thickness = mesh.sdf_thickness_at(origin, normal, 8, 30f32.to_radians(), &mut rng);
+
+        assert!(thickness.is_none());
+    }
+
+    #[test]
+    fn calculate_thickness_skips_degenerate_triangles_without_panicking() {
+        // The two opposing plates, plus a zero-area triangle (all three
+        // indices the same vertex) that must be skipped rather than
+        // panicking when `triangle.normal()` returns `None`.
+        let indices: [u32; 9] = [0, 1, 2, 3, 4, 5, 0, 0, 0];
+        let mesh = StlMesh::new(&OPPOSING_PLATES_VERTICES, &indices);
+
+        let stats = mesh.calculate_thickness(None, 16, 30.0);
+
+        assert!(!stats.thicknesses.is_empty());
+        assert!(stats.avg > 0.0);
+    }
+
+    #[test]
+    fn calculate_thickness_accepts_opposing_normal_hits() {
+        let mesh = StlMesh::new(&OPPOSING_PLATES_VERTICES, &OPPOSING_PLATES_INDICES);
+
+        let stats = mesh.calculate_thickness(None, 24, 30.0);
+
+        assert!(!stats.thicknesses.is_empty());
+        assert!(stats.avg > 0.0 && stats.avg < 5.0);
+    }
+
+    #[test]
+    fn calculate_thickness_rejects_same_facing_hits() {
+        let mesh = StlMesh::new(&SAME_FACING_PLATES_VERTICES, &SAME_FACING_PLATES_INDICES);
+
+        let stats = mesh.calculate_thickness(None, 16, 30.0);
+
+        assert!(stats.thicknesses.is_empty());
+    }
+}
@@ -0,0 +1,141 @@
+//! Format-agnostic mesh ingestion: dispatches on file extension and builds
+//! a [`StlMesh`] from STL, OBJ, or PLY files, so the rest of the pipeline
+//! never has to care which format a file came in as.
+
+use std::convert::TryFrom;
+use std::fs::OpenOptions;
+use std::io;
+use std::path::Path;
+
+use ply_rs::parser::Parser;
+use ply_rs::ply::{DefaultElement, Property};
+
+use crate::geometry::{PlyTriangleMesh, StlMesh};
+
+/// Load a mesh from an STL, OBJ, or PLY file, dispatching on extension.
+pub fn load_mesh(path: &Path) -> io::Result<StlMesh> {
+    match path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_ascii_lowercase())
+        .as_deref()
+    {
+        Some("stl") => {
+            let mut file = OpenOptions::new().read(true).open(path)?;
+            let stl = stl_io::read_stl(&mut file)?;
+            Ok(StlMesh::from(&stl))
+        }
+        Some("obj") => {
+            let mesh = parse_obj(path)?;
+            StlMesh::try_from(&mesh)
+        }
+        Some("ply") => {
+            let mesh = parse_ply(path)?;
+            StlMesh::try_from(&mesh)
+        }
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("Unsupported mesh file extension: {}", path.display()),
+        )),
+    }
+}
+
+/// Parse an OBJ file's first model into a `tobj::Mesh`, without
+/// triangulating: `StlMesh`'s `TryFrom` impl fan-triangulates polygon
+/// faces itself.
+fn parse_obj(path: &Path) -> io::Result<tobj::Mesh> {
+    let (models, _materials) = tobj::load_obj(
+        path,
+        &tobj::LoadOptions {
+            triangulate: false,
+            single_index: true,
+            ..Default::default()
+        },
+    )
+    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+
+    models
+        .into_iter()
+        .next()
+        .map(|model| model.mesh)
+        .ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "OBJ file contains no meshes")
+        })
+}
+
+/// Parse a PLY file's `vertex` and `face` elements into a
+/// [`PlyTriangleMesh`], leaving polygon faces untriangulated.
+fn parse_ply(path: &Path) -> io::Result<PlyTriangleMesh> {
+    let mut file = OpenOptions::new().read(true).open(path)?;
+    let parser = Parser::<DefaultElement>::new();
+    let ply = parser
+        .read_ply(&mut file)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+
+    let vertices = match ply.payload.get("vertex") {
+        Some(elems) => elems
+            .iter()
+            .map(|v| {
+                Ok([
+                    property_as_f32(v.get("x"))?,
+                    property_as_f32(v.get("y"))?,
+                    property_as_f32(v.get("z"))?,
+                ])
+            })
+            .collect::<io::Result<Vec<[f32; 3]>>>()?,
+        None => Vec::new(),
+    };
+
+    let polygons = ply
+        .payload
+        .get("face")
+        .map(|elems| {
+            elems
+                .iter()
+                .map(|f| {
+                    f.get("vertex_indices")
+                        .or_else(|| f.get("vertex_index"))
+                        .and_then(property_as_index_list)
+                        .unwrap_or_default()
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(PlyTriangleMesh { vertices, polygons })
+}
+
+/// Convert a PLY scalar property to `f32`, accepting any of the format's
+/// numeric encodings (float/double and all signed/unsigned integer
+/// widths). Unsupported property kinds (lists, or a missing property)
+/// error out rather than silently defaulting to `0.0`, since a
+/// mistakenly-zeroed vertex is a worse failure mode than refusing to load.
+fn property_as_f32(prop: Option<&Property>) -> io::Result<f32> {
+    match prop {
+        Some(Property::Float(v)) => Ok(*v),
+        Some(Property::Double(v)) => Ok(*v as f32),
+        Some(Property::Char(v)) => Ok(*v as f32),
+        Some(Property::UChar(v)) => Ok(*v as f32),
+        Some(Property::Short(v)) => Ok(*v as f32),
+        Some(Property::UShort(v)) => Ok(*v as f32),
+        Some(Property::Int(v)) => Ok(*v as f32),
+        Some(Property::UInt(v)) => Ok(*v as f32),
+        Some(other) => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Unsupported PLY vertex property type: {other:?}"),
+        )),
+        None => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "PLY vertex is missing an expected x/y/z property",
+        )),
+    }
+}
+
+fn property_as_index_list(prop: &Property) -> Option<Vec<u32>> {
+    match prop {
+        Property::ListInt(v) => Some(v.iter().map(|i| *i as u32).collect()),
+        Property::ListUInt(v) => Some(v.clone()),
+        Property::ListUChar(v) => Some(v.iter().map(|i| *i as u32).collect()),
+        _ => None,
+    }
+}
@@ -1,11 +1,19 @@
 mod geometry;
+mod loader;
 use rayon::prelude::*;
-use std::fs::OpenOptions;
-use std::io::Result;
+use std::io::{Result, Write};
 use std::path::PathBuf;
 
-use clap::Parser;
-use geometry::{StlMesh, VolumeInfo};
+use clap::{Parser, ValueEnum};
+use geometry::VolumeInfo;
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum OutputFormat {
+    Table,
+    Json,
+    Csv,
+    Ndjson,
+}
 
 #[derive(Parser)]
 #[command(author, version, about, long_about = None, arg_required_else_help = true)]
@@ -13,44 +21,229 @@ struct Args {
     /// Absolute path(s) to directory containing stl files or individual .stl files.
     /// Examples: rusty-stl /path/to/folder some_file.stl
     paths: Vec<std::path::PathBuf>,
+
+    /// Output format: a fixed-width table, a JSON array, CSV, or NDJSON
+    /// (one JSON object per line, streamed as each file finishes).
+    #[arg(long, value_enum, default_value = "table")]
+    format: OutputFormat,
+
+    /// Include the full per-triangle thickness samples in json/ndjson output.
+    #[arg(long)]
+    verbose: bool,
+
+    /// Maximum recursion depth when scanning a directory (0 = that
+    /// directory only, no descent into subdirectories).
+    #[arg(long, default_value_t = 0)]
+    max_depth: usize,
+
+    /// Glob pattern to exclude from the scan; may be repeated.
+    #[arg(long = "exclude")]
+    exclude: Vec<String>,
+
+    /// Skip files smaller than this many bytes.
+    #[arg(long)]
+    min_size: Option<u64>,
+
+    /// Skip files larger than this many bytes.
+    #[arg(long)]
+    max_size: Option<u64>,
+
+    /// Also write each processed file's convex hull as a binary STL under
+    /// this directory, mirroring the input's own directory path beneath it,
+    /// named `<stem>_convex.stl`.
+    #[arg(long, value_name = "DIR")]
+    write_convex: Option<PathBuf>,
+}
+
+/// A single file's results paired with its display filename, for
+/// structured (json/ndjson) output.
+#[derive(serde::Serialize)]
+struct FileRecord<'a> {
+    filename: &'a str,
+    #[serde(flatten)]
+    info: &'a VolumeInfo,
+}
+
+/// Flat per-file row for CSV output, since CSV has no room for the nested
+/// `thicknesses` sample vector.
+#[derive(serde::Serialize)]
+struct CsvRecord<'a> {
+    filename: &'a str,
+    mesh_volume: f32,
+    bounding_box_volume: f32,
+    convex_volume: f32,
+    thickness_avg: f32,
+    thickness_median: f32,
+    thickness_std_dev: f32,
+    monte_carlo_volume: Option<f32>,
+    monte_carlo_standard_error: Option<f32>,
+}
+
+impl<'a> CsvRecord<'a> {
+    fn new(filename: &'a str, info: &VolumeInfo) -> CsvRecord<'a> {
+        CsvRecord {
+            filename,
+            mesh_volume: info.mesh,
+            bounding_box_volume: info.bounding_box,
+            convex_volume: info.convex_volume,
+            thickness_avg: info.thickness.avg,
+            thickness_median: info.thickness.median,
+            thickness_std_dev: info.thickness.std_dev,
+            monte_carlo_volume: info.monte_carlo_volume.map(|(v, _)| v),
+            monte_carlo_standard_error: info.monte_carlo_volume.map(|(_, se)| se),
+        }
+    }
 }
 
 type FileList = Vec<std::path::PathBuf>;
 
-fn get_filenames(args: Args) -> FileList {
-    use glob::glob;
-    let mut files: FileList = Vec::new();
+/// Mesh file extensions the loader understands.
+const SUPPORTED_EXTENSIONS: [&str; 3] = ["stl", "obj", "ply"];
+
+fn get_filenames(args: &Args) -> FileList {
+    use glob::Pattern;
+
+    let excludes: Vec<Pattern> = args
+        .exclude
+        .iter()
+        .filter_map(|pattern| match Pattern::new(pattern) {
+            Ok(p) => Some(p),
+            Err(e) => {
+                println!("Invalid --exclude pattern {pattern:?}: {e}");
+                None
+            }
+        })
+        .collect();
 
+    let mut files: FileList = Vec::new();
     for path in args.paths.iter() {
         if path.is_dir() {
-            let pstr = path.to_str().unwrap();
-            let pstar = format!("{pstr}/*.stl");
-            for entry in glob(&pstar).unwrap() {
-                match entry {
-                    Ok(p) => files.push(p),
-                    Err(e) => println!("{:?}", e),
-                }
-            }
-        }
-        if path.is_file() {
+            walk_dir(path, args.max_depth, &excludes, args, &mut files);
+        } else if path.is_file()
+            && !is_excluded(path, &excludes)
+            && passes_size_filters(path, args)
+        {
             files.push(path.to_path_buf());
         }
     }
     files
 }
 
-fn process_file(path: &PathBuf) -> Result<VolumeInfo> {
-    let mut file = OpenOptions::new().read(true).open(path)?;
-    let stl = stl_io::read_stl(&mut file)?;
-    let stl_mesh: StlMesh = StlMesh::new_from_indexed_mesh(&stl);
+/// Recursively walk `dir`, honoring `depth_remaining` (0 = this directory
+/// only), dropping paths matched by `excludes`, and keeping only supported
+/// mesh files within the configured size band.
+fn walk_dir(
+    dir: &std::path::Path,
+    depth_remaining: usize,
+    excludes: &[glob::Pattern],
+    args: &Args,
+    files: &mut FileList,
+) {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) => {
+            println!("Error reading directory {} - {}", dir.display(), e);
+            return;
+        }
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if is_excluded(&path, excludes) {
+            continue;
+        }
+
+        if path.is_dir() {
+            if depth_remaining > 0 {
+                walk_dir(&path, depth_remaining - 1, excludes, args, files);
+            }
+        } else if path.is_file()
+            && has_supported_extension(&path)
+            && passes_size_filters(&path, args)
+        {
+            files.push(path);
+        }
+    }
+}
+
+fn is_excluded(path: &std::path::Path, excludes: &[glob::Pattern]) -> bool {
+    let path_str = path.to_string_lossy();
+    excludes.iter().any(|pattern| pattern.matches(&path_str))
+}
+
+fn has_supported_extension(path: &std::path::Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .map(|e| SUPPORTED_EXTENSIONS.contains(&e.to_ascii_lowercase().as_str()))
+        .unwrap_or(false)
+}
+
+fn passes_size_filters(path: &std::path::Path, args: &Args) -> bool {
+    let Ok(metadata) = std::fs::metadata(path) else {
+        return true;
+    };
+    let size = metadata.len();
+    if let Some(min_size) = args.min_size {
+        if size < min_size {
+            return false;
+        }
+    }
+    if let Some(max_size) = args.max_size {
+        if size > max_size {
+            return false;
+        }
+    }
+    true
+}
+
+fn process_file(path: &PathBuf, write_convex_dir: Option<&std::path::Path>) -> Result<VolumeInfo> {
+    let stl_mesh = loader::load_mesh(path)?;
+
+    if let Some(dir) = write_convex_dir {
+        let target = convex_output_path(dir, path);
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        stl_mesh.convex().write_stl(&target)?;
+    }
+
     let info: VolumeInfo = stl_mesh.into();
     Ok(info)
 }
 
-fn process_files(files: &[PathBuf]) -> Vec<(&PathBuf, VolumeInfo)> {
+/// Mirror an input file's directory structure under `write_convex_dir`
+/// (dropping any root/prefix/`..` components so the output can't escape
+/// it), rather than flattening to a sanitized basename: two inputs that
+/// differ only in which directory they live in (`a/b.stl` vs `a_b.stl`,
+/// `partA/model.stl` vs `partB/model.stl`) must never collide on the same
+/// output file -- which, processed concurrently via `par_iter`, would
+/// otherwise mean two threads writing the same STL at once.
+fn convex_output_path(write_convex_dir: &std::path::Path, input: &std::path::Path) -> PathBuf {
+    let relative: PathBuf = input
+        .components()
+        .filter(|c| matches!(c, std::path::Component::Normal(_)))
+        .collect();
+
+    let stem = relative
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("mesh");
+
+    let mut target = match relative.parent() {
+        Some(parent) => write_convex_dir.join(parent),
+        None => write_convex_dir.to_path_buf(),
+    };
+    target.push(format!("{stem}_convex.stl"));
+    target
+}
+
+fn process_files<'a>(
+    files: &'a [PathBuf],
+    write_convex_dir: Option<&std::path::Path>,
+) -> Vec<(&'a PathBuf, VolumeInfo)> {
     files
         .par_iter()
-        .flat_map(|path| match process_file(path) {
+        .flat_map(|path| match process_file(path, write_convex_dir) {
             Err(e) => {
                 println!("Error opening file {} - {}", path.display(), e);
                 Option::None
@@ -60,11 +253,7 @@ fn process_files(files: &[PathBuf]) -> Vec<(&PathBuf, VolumeInfo)> {
         .collect()
 }
 
-fn main() -> Result<()> {
-    let args = Args::parse();
-    let files = get_filenames(args);
-    let volumes = process_files(files.as_slice());
-
+fn print_table(volumes: &[(&PathBuf, VolumeInfo)]) {
     println!(
         "{:<70} | {:<20} | {:<20} | {:<20} | {:<20}",
         "Filename", "Mesh volume", "Bounding box volume", "Convex volume", "Thickness"
@@ -78,9 +267,92 @@ fn main() -> Result<()> {
             vol.mesh / 1e6,
             vol.bounding_box / 1e6,
             vol.convex_volume / 1e6,
-            vol.thickness
+            vol.thickness.avg
         );
     }
+}
+
+fn print_json(volumes: &[(&PathBuf, VolumeInfo)]) -> Result<()> {
+    let records: Vec<FileRecord> = volumes
+        .iter()
+        .map(|(path, info)| FileRecord {
+            filename: path.file_name().unwrap().to_str().unwrap(),
+            info,
+        })
+        .collect();
+
+    let json = serde_json::to_string_pretty(&records)?;
+    println!("{json}");
+    Ok(())
+}
+
+fn print_csv(volumes: &[(&PathBuf, VolumeInfo)]) -> Result<()> {
+    let mut writer = csv::Writer::from_writer(std::io::stdout());
+    for (path, info) in volumes.iter() {
+        let filename = path.file_name().unwrap().to_str().unwrap();
+        writer.serialize(CsvRecord::new(filename, info))?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Emit one JSON object per line as each parallel task completes, so large
+/// batches stream instead of buffering the whole result set.
+fn stream_ndjson(files: &[PathBuf], verbose: bool, write_convex_dir: Option<&std::path::Path>) {
+    let stdout = std::io::stdout();
+    files
+        .par_iter()
+        .for_each(|path| match process_file(path, write_convex_dir) {
+            Err(e) => println!("Error opening file {} - {}", path.display(), e),
+            Ok(mut info) => {
+                if !verbose {
+                    info.thickness.thicknesses.clear();
+                }
+                let filename = path.file_name().unwrap().to_str().unwrap();
+                let record = FileRecord {
+                    filename,
+                    info: &info,
+                };
+                match serde_json::to_string(&record) {
+                    Ok(line) => {
+                        let mut lock = stdout.lock();
+                        let _ = writeln!(lock, "{line}");
+                    }
+                    Err(e) => println!("Error serializing {} - {}", path.display(), e),
+                }
+            }
+        });
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let format = args.format;
+    let verbose = args.verbose;
+    let files = get_filenames(&args);
+
+    if let Some(dir) = &args.write_convex {
+        std::fs::create_dir_all(dir)?;
+    }
+    let write_convex_dir = args.write_convex.as_deref();
+
+    if matches!(format, OutputFormat::Ndjson) {
+        stream_ndjson(files.as_slice(), verbose, write_convex_dir);
+        return Ok(());
+    }
+
+    let mut volumes = process_files(files.as_slice(), write_convex_dir);
+    if !verbose {
+        for (_, info) in volumes.iter_mut() {
+            info.thickness.thicknesses.clear();
+        }
+    }
+
+    match format {
+        OutputFormat::Table => print_table(&volumes),
+        OutputFormat::Json => print_json(&volumes)?,
+        OutputFormat::Csv => print_csv(&volumes)?,
+        OutputFormat::Ndjson => unreachable!("handled above"),
+    }
 
     Ok(())
 }